@@ -1,41 +1,79 @@
 pub mod exhaustive_search;
 pub mod solution;
 
-/// Iterator that yields every possible `nums` of a given length.
+/// Iterator that yields every possible `nums` of a given length in the half-open range
+/// `[start, end)` of its underlying numeric representation.
+///
+/// `remaining` (rather than `end`) tracks how many values are left to yield, as a `u128`: for
+/// `len == 64`, the exclusive upper bound of the full range is `2^64`, which doesn't fit in a
+/// `u64`, but the *count* of values in `[0, 2^64)` does fit in a `u128`. This lets [nums_iter]
+/// cover the full `[3, 64]` length range without `nums_iter_range` having to give up its `u64`
+/// `start`/`end` parameters, which are never asked to express a bound that large.
 pub struct NumsIter {
     len: usize,
-    max: i32,
-    next: i32,
+    next: u64,
+    remaining: u128,
 }
 
 impl Iterator for NumsIter {
     type Item = Vec<i32>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.next >= self.max {
+        if self.remaining == 0 {
             return None;
         }
-        self.next += 1;
 
         // Build a `nums` value out of the bits of `self.next`. For sanity, assign the
         // least-significant bit the lowest index in `nums`.
         let mut nums = Vec::with_capacity(self.len);
         for digit in (0..self.len).rev() {
-            nums.push((self.next >> digit) % 2);
+            nums.push(((self.next >> digit) % 2) as i32);
         }
+        // `wrapping_add` because `self.next` legitimately reaches `u64::MAX` when `len == 64`;
+        // `remaining` (checked above) is what actually stops iteration, so the wrapped value is
+        // never read.
+        self.next = self.next.wrapping_add(1);
+        self.remaining -= 1;
         Some(nums)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining.min(usize::MAX as u128) as usize;
+        (remaining, Some(remaining))
+    }
 }
 
+impl ExactSizeIterator for NumsIter {}
+
 /// Returns an iterator over all possible `nums` of length `len`.
 pub fn nums_iter(len: u8) -> NumsIter {
     // Edge cases:
-    // 0 => max = 0, so iterator never yields values
+    // 0 => remaining = 1, so iterator yields a single empty `nums`
     // [1, 2] => iterator yields values, but algorithms should always return -1
-    // [33, usize::MAX] => implementations may panic
+    // [64, usize::MAX] => implementations may panic
+    match len {
+        // `2_u64.pow(64)` would overflow, since `2^64` doesn't fit in a `u64`; build the count
+        // directly as a `u128` instead of routing through `nums_iter_range`'s `u64` bound.
+        64 => NumsIter {
+            len: len as usize,
+            next: 0,
+            remaining: 1_u128 << 64,
+        },
+        len => nums_iter_range(len, 0, 2_u64.pow(len as u32)),
+    }
+}
+
+/// Returns an iterator over the possible `nums` of length `len` whose numeric representation
+/// falls in the half-open range `[start, end)`. Pass `0..2_u64.pow(len as u32)` to cover every
+/// `nums` of that length except when `len == 64` (see [nums_iter], which handles that case
+/// directly); callers that want to split the full range across multiple workers can instead
+/// partition it into disjoint `[start, end)` sub-ranges. `start` and `end` are `u64` (rather than,
+/// say, `usize` or `i32`) so this can represent ranges across most of the `2^64` state space
+/// without overflowing, since `len` may be as large as 64.
+pub fn nums_iter_range(len: u8, start: u64, end: u64) -> NumsIter {
     NumsIter {
         len: len as usize,
-        max: 2_i32.pow(len as u32) - 1,
-        next: 0,
+        next: start,
+        remaining: (end as u128).saturating_sub(start as u128),
     }
 }
@@ -1,25 +1,112 @@
-use verify_leetcode_3191::exhaustive_search::exhaustive_search;
-use verify_leetcode_3191::nums_iter;
+use std::thread;
+
+use verify_leetcode_3191::exhaustive_search::{a_star_search, exhaustive_search, ida_star_search};
+use verify_leetcode_3191::nums_iter_range;
 use verify_leetcode_3191::solution::sliding_window;
 
+/// Number of worker threads to split each length's state space across.
+const WORKERS: usize = 8;
+
+/// Lengths beyond the dense search's 32-element ceiling used to spot-check [exhaustive_search]'s
+/// sparse `HashSet` mode. Brute-forcing every `nums` of these lengths, as `check_len` does for
+/// `1..=32`, would mean enumerating `2^33` states or more, so [check_sparse_spot] instead checks a
+/// handful of hand-picked, known-small-answer vectors at each length.
+const SPARSE_SPOT_CHECK_LENGTHS: [usize; 3] = [33, 48, 64];
+
+/// A search checked against [sliding_window], paired with the name used when reporting a
+/// mismatch.
+type NamedAlgorithm = (&'static str, fn(Vec<i32>, i32) -> i32);
+
+/// Every alternative search checked against [sliding_window] by [check_len] and
+/// [check_sparse_spot], paired with the name used when reporting a mismatch.
+const ALGORITHMS: [NamedAlgorithm; 3] = [
+    ("exhaustive_search", exhaustive_search),
+    ("a_star_search", a_star_search),
+    ("ida_star_search", ida_star_search),
+];
+
 fn main() {
-    for n in 1..=32 {
-        print!("Checking n = {n}... ");
-        let mut error_free = true;
-        for nums in nums_iter(5) {
-            let sw = sliding_window(nums.clone());
-            let es = exhaustive_search(nums.clone(), sw);
-            if sw != es {
-                if error_free {
-                    error_free = false;
-                    println!("Errors:");
-                }
-                println!("{nums:?}: {sw:4}{es:4}");
-            }
-        }
+    for len in 1..=32 {
+        print!("Checking n = {len}... ");
+        report(check_len(len, WORKERS));
+    }
+
+    for len in SPARSE_SPOT_CHECK_LENGTHS {
+        print!("Spot-checking n = {len} (sparse mode)... ");
+        report(check_sparse_spot(len));
+    }
+}
 
-        if error_free {
-            println!("All checks passed!");
+/// Prints the outcome of a batch of checks produced by [check_len] or [check_sparse_spot].
+fn report(mismatches: Vec<(Vec<i32>, &'static str, i32, i32)>) {
+    if mismatches.is_empty() {
+        println!("All checks passed!");
+    } else {
+        println!("Errors:");
+        for (nums, algorithm, sw, result) in mismatches {
+            println!("{nums:?}: sliding_window={sw:4} {algorithm}={result:4}");
         }
     }
 }
+
+/// Checks every possible `nums` of length `len` against every entry in [ALGORITHMS], partitioning
+/// the `2^len` states across `workers` threads, and returns every mismatch found.
+fn check_len(len: u8, workers: usize) -> Vec<(Vec<i32>, &'static str, i32, i32)> {
+    let total = 2_u64.pow(len as u32);
+    let chunk_size = total.div_ceil(workers as u64);
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|worker| {
+                let start = worker as u64 * chunk_size;
+                let end = (start + chunk_size).min(total);
+                scope.spawn(move || {
+                    let mut mismatches = Vec::new();
+                    for nums in nums_iter_range(len, start, end) {
+                        let sw = sliding_window(nums.clone());
+                        for (name, algorithm) in ALGORITHMS {
+                            let result = algorithm(nums.clone(), sw);
+                            if result != sw {
+                                mismatches.push((nums.clone(), name, sw, result));
+                            }
+                        }
+                    }
+                    mismatches
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("worker thread panicked"))
+            .collect()
+    })
+}
+
+/// Checks a few hand-picked `nums` of the given `len` (which must exceed 32) against every entry
+/// in [ALGORITHMS], exercising each search's sparse or fallback path instead of its dense one.
+/// Unlike [check_len], this doesn't attempt every possible `nums` of `len`: there are far too many
+/// once `len` crosses 32 to brute-force, so instead it checks vectors built by undoing a small,
+/// known number of flips from the all-ones goal, which keeps `sliding_window`'s (and therefore
+/// each search's `limit`) answer small and the check fast regardless of `len`.
+fn check_sparse_spot(len: usize) -> Vec<(Vec<i32>, &'static str, i32, i32)> {
+    let solved = vec![1; len];
+
+    let mut one_flip = vec![1; len];
+    one_flip.iter_mut().take(3).for_each(|b| *b ^= 1);
+
+    let mut two_flips = vec![1; len];
+    two_flips.iter_mut().take(3).for_each(|b| *b ^= 1);
+    two_flips.iter_mut().skip(5).take(3).for_each(|b| *b ^= 1);
+
+    [solved, one_flip, two_flips]
+        .into_iter()
+        .flat_map(|nums| {
+            let sw = sliding_window(nums.clone());
+            ALGORITHMS.into_iter().filter_map(move |(name, algorithm)| {
+                let result = algorithm(nums.clone(), sw);
+                (result != sw).then_some((nums.clone(), name, sw, result))
+            })
+        })
+        .collect()
+}
@@ -14,6 +14,25 @@ pub fn sliding_window(mut nums: Vec<i32>) -> i32 {
     ops
 }
 
+/// Like [sliding_window], but returns the `flip_index` values used to reach the all-ones state,
+/// or `None` if no solution exists.
+///
+/// `sliding_window`'s greedy left-to-right scan always flips at the lowest unresolved `0`, so it
+/// is already tracing out a single optimal solution; this variant just records the index of each
+/// flip it performs instead of discarding it.
+pub fn sliding_window_path(mut nums: Vec<i32>) -> Option<Vec<usize>> {
+    let mut flip_indices = Vec::new();
+    for i in 0..nums.len() {
+        if nums[i] == 0 {
+            if !flip(&mut nums, i) {
+                return None;
+            }
+            flip_indices.push(i);
+        }
+    }
+    Some(flip_indices)
+}
+
 /// Returns whether the requested flip was legal. If `true`, the flip was performed. If `false`,
 /// `nums` was not modified.
 fn flip(nums: &mut [i32], index: usize) -> bool {